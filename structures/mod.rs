@@ -0,0 +1,5 @@
+pub mod segmented_vec;
+pub mod vector;
+
+#[cfg(test)]
+pub(crate) mod test_support;
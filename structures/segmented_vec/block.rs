@@ -0,0 +1,77 @@
+use std::alloc::{self, Layout};
+use std::mem::{self, MaybeUninit};
+use std::ptr::NonNull;
+
+use super::BLOCK_CAP;
+
+/// A single fixed-capacity chunk of storage in a [`SegmentedVec`](super::SegmentedVec).
+///
+/// Blocks are linked into a doubly-linked chain by the owning
+/// `SegmentedVec`. Once allocated, a block's address never changes and
+/// its elements are never moved, which is what gives `SegmentedVec`
+/// stable element addresses and O(1) worst-case growth.
+pub(crate) struct Block<T> {
+    ptr: NonNull<MaybeUninit<T>>,
+    pub prev: Option<NonNull<Block<T>>>,
+    pub next: Option<NonNull<Block<T>>>,
+}
+
+impl<T> Block<T> {
+    fn layout() -> Layout {
+        Layout::array::<MaybeUninit<T>>(BLOCK_CAP).expect("block layout overflow")
+    }
+
+    /// Allocates a fresh, unlinked block and leaks it onto the heap,
+    /// returning a pointer the caller is responsible for eventually
+    /// reclaiming via [`free`](Self::free).
+    pub fn new() -> NonNull<Block<T>> {
+        // A zero-size layout is UB to pass to `alloc`/`dealloc` (and some
+        // allocators return null for it, which would abort here for
+        // nothing); ZSTs need no element storage at all, so skip that
+        // allocation, same as `RawVec`. The `Block` header itself is still
+        // boxed below.
+        let ptr = if mem::size_of::<T>() == 0 {
+            NonNull::dangling()
+        } else {
+            let layout = Self::layout();
+            let raw = unsafe { alloc::alloc(layout) };
+            match NonNull::new(raw.cast::<MaybeUninit<T>>()) {
+                Some(ptr) => ptr,
+                None => alloc::handle_alloc_error(layout),
+            }
+        };
+        let block = Block {
+            ptr,
+            prev: None,
+            next: None,
+        };
+        NonNull::from(Box::leak(Box::new(block)))
+    }
+
+    /// Reclaims a block previously returned by [`new`](Self::new).
+    ///
+    /// # Safety
+    ///
+    /// The caller must have already dropped any initialized elements it
+    /// holds, and must not use `this` (or any slot pointer derived from
+    /// it) afterwards.
+    pub unsafe fn free(this: NonNull<Block<T>>) {
+        unsafe { drop(Box::from_raw(this.as_ptr())) };
+    }
+
+    /// Returns a pointer to the element slot at `offset`, which must be
+    /// `< BLOCK_CAP`.
+    #[inline]
+    pub fn slot(&self, offset: usize) -> *mut T {
+        debug_assert!(offset < BLOCK_CAP);
+        unsafe { self.ptr.as_ptr().add(offset).cast() }
+    }
+}
+
+impl<T> Drop for Block<T> {
+    fn drop(&mut self) {
+        if mem::size_of::<T>() != 0 {
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), Self::layout()) }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod block;
+mod vec;
+
+pub use vec::SegmentedVec;
+
+/// The number of elements stored per [`Block`](block::Block). Growth
+/// appends a new block of this size rather than reallocating and
+/// copying everything seen so far.
+const BLOCK_CAP: usize = 64;
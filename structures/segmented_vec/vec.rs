@@ -0,0 +1,272 @@
+use std::marker::PhantomData;
+use std::ops::Index;
+use std::ptr;
+use std::ptr::NonNull;
+
+use super::block::Block;
+use super::BLOCK_CAP;
+
+/// A vector that grows by chaining fixed-capacity blocks instead of
+/// reallocating and copying one contiguous buffer.
+///
+/// Appending a new block is O(1) worst-case and never moves previously
+/// written elements, at the cost of `index` needing to walk `i / BLOCK_CAP`
+/// blocks from the head rather than a single pointer offset. This suits
+/// workloads that grow very large buffers incrementally and cannot
+/// tolerate the latency spikes of [`Vec`](super::super::vector::vec::Vec)'s
+/// doubling reallocation.
+pub struct SegmentedVec<T> {
+    head: Option<NonNull<Block<T>>>,
+    tail: Option<NonNull<Block<T>>>,
+    /// Number of initialized elements in the tail block. Every block
+    /// other than the tail is always completely full.
+    tail_len: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> SegmentedVec<T> {
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            tail_len: 0,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.tail.is_none() || self.tail_len == BLOCK_CAP {
+            self.push_block();
+        }
+
+        // SAFETY: the block above was just (re)established as non-full.
+        let tail = self.tail.unwrap();
+        unsafe { ptr::write(tail.as_ref().slot(self.tail_len), value) };
+        self.tail_len += 1;
+        self.len += 1;
+    }
+
+    fn push_block(&mut self) {
+        let block = Block::new();
+        match self.tail {
+            Some(mut tail) => unsafe {
+                tail.as_mut().next = Some(block);
+                (*block.as_ptr()).prev = Some(tail);
+            },
+            None => self.head = Some(block),
+        }
+        self.tail = Some(block);
+        self.tail_len = 0;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        self.tail_len -= 1;
+        // SAFETY: `tail_len` indexes the last initialized slot in the tail
+        // block before decrementing.
+        let value = unsafe { ptr::read(tail.as_ref().slot(self.tail_len)) };
+        self.len -= 1;
+
+        if self.tail_len == 0 {
+            self.free_tail_block();
+        }
+
+        Some(value)
+    }
+
+    /// Frees the (now fully drained) tail block and makes its
+    /// predecessor, which is always full, the new tail.
+    fn free_tail_block(&mut self) {
+        let tail = self.tail.take().expect("tail block exists while popping");
+        let prev = unsafe { tail.as_ref().prev };
+        unsafe { Block::free(tail) };
+
+        match prev {
+            Some(mut prev) => {
+                unsafe { prev.as_mut().next = None };
+                self.tail = Some(prev);
+                self.tail_len = BLOCK_CAP;
+            }
+            None => {
+                self.head = None;
+                self.tail_len = 0;
+            }
+        }
+    }
+
+    fn block_at(&self, block_idx: usize) -> NonNull<Block<T>> {
+        let mut cur = self.head.expect("index in bounds implies a block chain");
+        for _ in 0..block_idx {
+            cur = unsafe { cur.as_ref().next }.expect("index in bounds implies enough blocks");
+        }
+        cur
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let block = self.block_at(index / BLOCK_CAP);
+        Some(unsafe { &*block.as_ref().slot(index % BLOCK_CAP) })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let block = self.block_at(index / BLOCK_CAP);
+        Some(unsafe { &mut *block.as_ref().slot(index % BLOCK_CAP) })
+    }
+
+    /// Iterates over the vector's contents one block at a time, exposing
+    /// each fully/partially filled block as a contiguous `&[T]` slice so
+    /// hot loops can process runs without per-element indirection.
+    pub fn blocks(&self) -> Blocks<'_, T> {
+        Blocks {
+            cur: self.head,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for SegmentedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for SegmentedVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> Drop for SegmentedVec<T> {
+    fn drop(&mut self) {
+        let mut remaining = self.len;
+        let mut cur = self.head;
+        while let Some(block) = cur {
+            let count = remaining.min(BLOCK_CAP);
+            for i in 0..count {
+                unsafe { ptr::drop_in_place(block.as_ref().slot(i)) };
+            }
+            remaining -= count;
+            let next = unsafe { block.as_ref().next };
+            unsafe { Block::free(block) };
+            cur = next;
+        }
+    }
+}
+
+/// An iterator over the contiguous `&[T]` runs backing a [`SegmentedVec`].
+pub struct Blocks<'a, T> {
+    cur: Option<NonNull<Block<T>>>,
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Blocks<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let block = self.cur?;
+        let count = self.remaining.min(BLOCK_CAP);
+        self.remaining -= count;
+        self.cur = unsafe { block.as_ref().next };
+        Some(unsafe { std::slice::from_raw_parts(block.as_ref().slot(0), count) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::test_support::DropCounter;
+    use std::cell::Cell;
+
+    #[test]
+    fn push_across_many_blocks() {
+        let mut v = SegmentedVec::<usize>::new();
+        for i in 0..(BLOCK_CAP * 3 + 5) {
+            v.push(i);
+        }
+        assert_eq!(v.len(), BLOCK_CAP * 3 + 5);
+        for i in 0..v.len() {
+            assert_eq!(*v.get(i).unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn zst_elements_push_and_pop_across_blocks() {
+        let mut v = SegmentedVec::<()>::new();
+        for _ in 0..(BLOCK_CAP * 2 + 1) {
+            v.push(());
+        }
+        assert_eq!(v.len(), BLOCK_CAP * 2 + 1);
+        assert_eq!(v.get(0), Some(&()));
+        for _ in 0..(BLOCK_CAP * 2 + 1) {
+            assert_eq!(v.pop(), Some(()));
+        }
+        assert_eq!(v.pop(), None);
+    }
+
+    #[test]
+    fn pop_frees_empty_trailing_blocks() {
+        let mut v = SegmentedVec::<usize>::new();
+        for i in 0..(BLOCK_CAP * 2) {
+            v.push(i);
+        }
+        for i in (0..(BLOCK_CAP * 2)).rev() {
+            assert_eq!(v.pop(), Some(i));
+        }
+        assert_eq!(v.pop(), None);
+        assert_eq!(v.len(), 0);
+
+        // the chain must still be usable after being fully drained
+        v.push(42);
+        assert_eq!(v.get(0), Some(&42));
+    }
+
+    #[test]
+    fn blocks_iterator_yields_contiguous_runs() {
+        let mut v = SegmentedVec::<usize>::new();
+        for i in 0..(BLOCK_CAP + 3) {
+            v.push(i);
+        }
+        let runs: std::vec::Vec<&[usize]> = v.blocks().collect();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].len(), BLOCK_CAP);
+        assert_eq!(runs[1].len(), 3);
+        assert_eq!(runs[1], [BLOCK_CAP, BLOCK_CAP + 1, BLOCK_CAP + 2]);
+    }
+
+    #[test]
+    fn drop_runs_for_every_live_element() {
+        let count = Cell::new(0);
+        {
+            let mut v = SegmentedVec::new();
+            for _ in 0..(BLOCK_CAP + 10) {
+                v.push(DropCounter(&count));
+            }
+            v.pop();
+        }
+        assert_eq!(count.get(), BLOCK_CAP + 10);
+    }
+}
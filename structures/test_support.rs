@@ -0,0 +1,14 @@
+//! Test-only helpers shared by the `vector` and `segmented_vec` trees.
+#![cfg(test)]
+
+use std::cell::Cell;
+
+/// Increments a shared counter every time one is dropped, so a test can
+/// assert exactly how many destructors a collection ran.
+pub(crate) struct DropCounter<'a>(pub &'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
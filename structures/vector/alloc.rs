@@ -0,0 +1,130 @@
+use std::alloc::{self, Layout};
+use std::ptr::{self, NonNull};
+
+/// Signals that an [`Allocator`] could not satisfy a memory request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+/// A source of raw, uninitialized memory.
+///
+/// Decoupling allocation from `RawVec` this way lets a [`Vec`](super::vec::Vec)
+/// be backed by an arena, a bump allocator, or a fixed region instead of
+/// the global heap, which `no_std`/embedded users in particular need.
+///
+/// # Safety
+///
+/// Implementors must return memory that satisfies the requested `Layout`
+/// and must not deallocate memory still owned by a live allocation.
+pub unsafe trait Allocator {
+    /// Allocates at least `layout.size()` bytes aligned to `layout.align()`.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError>;
+
+    /// Deallocates memory previously returned by [`allocate`](Self::allocate)
+    /// (or [`grow`](Self::grow)/[`shrink`](Self::shrink)) with the same `layout`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator with `layout`, and
+    /// must not be used again afterwards.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grows a previous allocation from `old_layout` to `new_layout`,
+    /// preserving the first `old_layout.size()` bytes.
+    ///
+    /// The default implementation allocates fresh memory, copies the old
+    /// contents over, and deallocates the old block; allocators that can
+    /// do better (e.g. via `realloc`) should override it.
+    ///
+    /// # Safety
+    ///
+    /// Same preconditions as [`deallocate`](Self::deallocate), and
+    /// `new_layout.size() >= old_layout.size()`.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr().cast(), old_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+
+    /// Shrinks a previous allocation from `old_layout` to `new_layout`,
+    /// preserving the first `new_layout.size()` bytes.
+    ///
+    /// # Safety
+    ///
+    /// Same preconditions as [`deallocate`](Self::deallocate), and
+    /// `new_layout.size() <= old_layout.size()`.
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr().cast(), new_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+}
+
+/// The default [`Allocator`]: Rust's global heap, as exposed by `std::alloc`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(NonNull::dangling(), 0));
+        }
+        let raw = unsafe { alloc::alloc(layout) };
+        let ptr = NonNull::new(raw).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+        if old_layout.size() == 0 {
+            return self.allocate(new_layout);
+        }
+        let raw = unsafe { alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+        let ptr = NonNull::new(raw).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+        if new_layout.size() == 0 {
+            unsafe { self.deallocate(ptr, old_layout) };
+            return Ok(NonNull::slice_from_raw_parts(NonNull::dangling(), 0));
+        }
+        let raw = unsafe { alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+        let ptr = NonNull::new(raw).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+}
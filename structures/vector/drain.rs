@@ -0,0 +1,176 @@
+use std::ops::{Bound, Range, RangeBounds};
+use std::{ptr, slice};
+
+use super::alloc::{Allocator, Global};
+use super::vec::Vec;
+
+/// A draining iterator over a sub-range of a [`Vec`], created by
+/// [`Vec::drain`].
+///
+/// Removing `[start, end)` happens in two steps: construction truncates
+/// the source `Vec` to `start` immediately, so a leaked `Drain` (e.g. via
+/// `mem::forget`) leaves the vector merely shorter rather than exposing
+/// duplicated or uninitialized elements. `Drop` then drops whatever the
+/// caller never consumed and shifts the untouched tail `[end, len)` down
+/// to `start`, restoring the final length.
+pub struct Drain<'a, T, A: Allocator = Global> {
+    iter: slice::Iter<'a, T>,
+    tail_start: *const T,
+    tail_len: usize,
+    vec: &'a mut Vec<T, A>,
+}
+
+fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n + 1,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    start..end
+}
+
+impl<T, A: Allocator> Vec<T, A> {
+    /// Removes and returns an iterator yielding the elements in `range`,
+    /// shifting the tail back down to close the gap once the iterator is
+    /// dropped (whether or not it was fully consumed).
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = to_range(range, len);
+        assert!(start <= end && end <= len, "drain range out of bounds");
+
+        unsafe {
+            let base = self.as_ptr();
+            let drained = slice::from_raw_parts(base.add(start), end - start);
+            let tail_start = base.add(end);
+            let tail_len = len - end;
+
+            // Truncate up front; see the type-level doc comment for why.
+            self.set_len(start);
+
+            Drain {
+                iter: drained.iter(),
+                tail_start,
+                tail_len,
+                vec: self,
+            }
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|item| unsafe { ptr::read(item) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|item| unsafe { ptr::read(item) })
+    }
+}
+
+impl<'a, T, A: Allocator> ExactSizeIterator for Drain<'a, T, A> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T, A: Allocator> Drop for Drain<'a, T, A> {
+    fn drop(&mut self) {
+        // Drop any elements the caller never consumed.
+        for _ in self.by_ref() {}
+
+        // SAFETY: `start` is exactly the length the source `Vec` was
+        // truncated to on construction, and `tail_start`/`tail_len`
+        // describe the still-initialized, untouched `[end, len)` range.
+        let start = self.vec.len();
+        unsafe {
+            if self.tail_len > 0 {
+                let dst = self.vec.as_mut_ptr().add(start);
+                ptr::copy(self.tail_start, dst, self.tail_len);
+            }
+            self.vec.set_len(start + self.tail_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_support::DropCounter;
+    use super::super::vec::Vec;
+    use std::cell::Cell;
+
+    #[test]
+    fn drain_partial_range_shifts_tail_down() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..6 {
+            v.push(i);
+        }
+        let drained: std::vec::Vec<usize> = v.drain(1..4).collect();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert_eq!(&v[..], &[0, 4, 5]);
+    }
+
+    #[test]
+    fn drain_full_range_empties_vec() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..4 {
+            v.push(i);
+        }
+        let drained: std::vec::Vec<usize> = v.drain(..).collect();
+        assert_eq!(drained, vec![0, 1, 2, 3]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn drain_empty_range_is_a_no_op() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..4 {
+            v.push(i);
+        }
+        let drained: std::vec::Vec<usize> = v.drain(2..2).collect();
+        assert!(drained.is_empty());
+        assert_eq!(&v[..], &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn drain_dropped_without_iterating_still_removes_range() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..6 {
+            v.push(i);
+        }
+        drop(v.drain(1..4));
+        assert_eq!(&v[..], &[0, 4, 5]);
+    }
+
+    #[test]
+    fn drain_drops_unyielded_elements() {
+        let count = Cell::new(0);
+        let mut v = Vec::new();
+        for _ in 0..6 {
+            v.push(DropCounter(&count));
+        }
+        // Consume (and immediately drop) only the first of the three
+        // drained elements; the other two must still be dropped when
+        // `Drain` itself drops.
+        let mut drain = v.drain(1..4);
+        drop(drain.next());
+        assert_eq!(count.get(), 1);
+        drop(drain);
+        assert_eq!(count.get(), 3);
+    }
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+use super::alloc::{Allocator, Global};
+use super::raw_vec::RawVec;
+
+/// An iterator that moves elements out of a [`Vec`](super::vec::Vec),
+/// consuming it by value.
+///
+/// Created by the `IntoIterator` implementation for `Vec<T, A>`.
+pub struct IntoIter<T, A: Allocator = Global> {
+    buf: RawVec<T, A>,
+    ptr: *const T,
+    end: *const T,
+    _marker: PhantomData<T>,
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    pub(crate) fn new(buf: RawVec<T, A>, len: usize) -> Self {
+        let ptr = buf.ptr();
+        // SAFETY: `len` elements are initialized starting at `ptr`, so
+        // offsetting by `len` lands exactly one-past-the-end, which is
+        // always a valid (if not dereferenceable) pointer.
+        let end = if mem::size_of::<T>() == 0 {
+            ptr.wrapping_byte_add(len)
+        } else {
+            unsafe { ptr.add(len) }
+        };
+        Self {
+            buf,
+            ptr,
+            end,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.ptr == self.end {
+            return None;
+        }
+
+        if mem::size_of::<T>() == 0 {
+            // No storage to read from; just conjure the ZST and advance
+            // the one-past-the-end accounting pointer by one "unit".
+            self.ptr = self.ptr.wrapping_byte_add(1);
+            Some(unsafe { ptr::read(NonNull::dangling().as_ptr()) })
+        } else {
+            unsafe {
+                let old = self.ptr;
+                self.ptr = self.ptr.add(1);
+                Some(ptr::read(old))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
+    fn len(&self) -> usize {
+        if mem::size_of::<T>() == 0 {
+            (self.end as usize).wrapping_sub(self.ptr as usize)
+        } else {
+            // SAFETY: `end` is always reachable from `ptr` by whole `T`
+            // steps, so the byte difference divides evenly.
+            (self.end as usize - self.ptr as usize) / mem::size_of::<T>()
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        // Drop any elements that were never yielded; the allocation
+        // itself is freed by `RawVec`'s own `Drop` impl.
+        for _ in self.by_ref() {}
+    }
+}
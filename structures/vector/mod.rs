@@ -0,0 +1,13 @@
+mod alloc;
+mod drain;
+mod into_iter;
+mod raw_vec;
+mod shared_slice;
+pub mod vec;
+
+pub use alloc::{AllocError, Allocator, Global};
+pub use drain::Drain;
+pub use into_iter::IntoIter;
+pub use raw_vec::TryReserveError;
+pub use shared_slice::SharedSlice;
+pub use vec::Vec;
@@ -0,0 +1,247 @@
+use std::alloc::{self, Layout};
+use std::cmp;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
+
+use super::alloc::{Allocator, Global};
+
+/// The error returned by the fallible `try_reserve`/`try_reserve_exact`
+/// family when growth cannot be satisfied.
+///
+/// Unlike the infallible `reserve`/`push` paths, which panic on capacity
+/// overflow and abort the process on allocation failure, this lets callers that must degrade gracefully under
+/// memory pressure recover instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The new capacity computation overflowed `usize` (or would exceed
+    /// `isize::MAX` bytes), so no allocation was attempted.
+    CapacityOverflow,
+    /// The allocator returned an error for the given `Layout`.
+    AllocError { layout: Layout },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            TryReserveError::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
+
+/// The minimum non-zero capacity used for the first allocation, matching
+/// the amortized growth strategy used by `try_reserve`/`reserve`: small
+/// elements get a few slots up front so repeated single-element pushes
+/// don't reallocate on every call.
+const fn min_non_zero_cap(elem_size: usize) -> usize {
+    if elem_size == 1 {
+        8
+    } else if elem_size <= 1024 {
+        4
+    } else {
+        1
+    }
+}
+
+/// Bails out with `CapacityOverflow` if `size` would exceed `isize::MAX`,
+/// since that's the hard limit the allocator API imposes on `Layout`.
+fn alloc_guard(size: usize) -> Result<(), TryReserveError> {
+    if size > isize::MAX as usize {
+        Err(TryReserveError::CapacityOverflow)
+    } else {
+        Ok(())
+    }
+}
+
+fn handle_error(err: TryReserveError) -> ! {
+    match err {
+        TryReserveError::CapacityOverflow => panic!("capacity overflow"),
+        TryReserveError::AllocError { layout } => alloc::handle_alloc_error(layout),
+    }
+}
+
+/// The raw owning allocation backing a [`Vec`](super::vec::Vec).
+///
+/// `RawVec` has no notion of the number of initialized elements; it only
+/// tracks the pointer, capacity, and the `A: Allocator` that produced
+/// them, leaving length bookkeeping and initialization to `Vec` itself.
+pub(crate) struct RawVec<T, A: Allocator = Global> {
+    ptr: NonNull<T>,
+    cap: usize,
+    alloc: A,
+    _marker: PhantomData<T>,
+}
+
+impl<T> RawVec<T, Global> {
+    pub const NEW: Self = Self::new();
+
+    const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    pub fn with_capacity_zeroed(capacity: usize) -> Self {
+        Self::with_capacity_zeroed_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> RawVec<T, A> {
+    pub const fn new_in(alloc: A) -> Self {
+        // ZSTs never actually allocate, so they report an unbounded capacity.
+        let cap = if mem::size_of::<T>() == 0 { usize::MAX } else { 0 };
+        Self {
+            ptr: NonNull::dangling(),
+            cap,
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::try_allocate_in(capacity, false, alloc).unwrap_or_else(|err| handle_error(err))
+    }
+
+    pub fn with_capacity_zeroed_in(capacity: usize, alloc: A) -> Self {
+        Self::try_allocate_in(capacity, true, alloc).unwrap_or_else(|err| handle_error(err))
+    }
+
+    #[inline]
+    pub fn ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    fn current_memory(&self) -> Option<(NonNull<u8>, Layout)> {
+        if mem::size_of::<T>() == 0 || self.cap == 0 {
+            None
+        } else {
+            // SAFETY: a non-zero capacity for a non-ZST always came from a
+            // successful `Layout::array::<T>` computation.
+            let layout = Layout::array::<T>(self.cap).unwrap();
+            Some((self.ptr.cast(), layout))
+        }
+    }
+
+    fn try_allocate_in(capacity: usize, zeroed: bool, alloc: A) -> Result<Self, TryReserveError> {
+        if mem::size_of::<T>() == 0 || capacity == 0 {
+            return Ok(Self::new_in(alloc));
+        }
+
+        let layout = Layout::array::<T>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        alloc_guard(layout.size())?;
+
+        let memory = alloc
+            .allocate(layout)
+            .map_err(|_| TryReserveError::AllocError { layout })?;
+        let ptr: NonNull<T> = memory.cast();
+        if zeroed {
+            unsafe { ptr.as_ptr().cast::<u8>().write_bytes(0, layout.size()) };
+        }
+
+        Ok(Self {
+            ptr,
+            cap: capacity,
+            alloc,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Grows (or shrinks-in-place is never done here) the allocation to
+    /// exactly `new_cap` elements, without any amortization.
+    fn try_grow_to(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        if mem::size_of::<T>() == 0 {
+            // ZSTs are already at `usize::MAX` capacity; nothing to do.
+            return Ok(());
+        }
+
+        let new_layout = Layout::array::<T>(new_cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+        alloc_guard(new_layout.size())?;
+
+        let memory = match self.current_memory() {
+            Some((old_ptr, old_layout)) => unsafe { self.alloc.grow(old_ptr, old_layout, new_layout) },
+            None => self.alloc.allocate(new_layout),
+        };
+        let memory = memory.map_err(|_| TryReserveError::AllocError { layout: new_layout })?;
+
+        self.ptr = memory.cast();
+        self.cap = new_cap;
+        Ok(())
+    }
+
+    /// Fallible core of [`reserve`](Self::reserve): grows the allocation,
+    /// amortized, so that at least `additional` more elements fit beyond
+    /// `len`.
+    pub fn try_reserve(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.cap.wrapping_sub(len) >= additional {
+            return Ok(());
+        }
+
+        let required_cap = len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let new_cap = cmp::max(self.cap * 2, required_cap);
+        let new_cap = cmp::max(min_non_zero_cap(mem::size_of::<T>()), new_cap);
+        self.try_grow_to(new_cap)
+    }
+
+    /// Fallible core of [`reserve_for_push`](Self::reserve_for_push).
+    pub fn try_reserve_for_push(&mut self, len: usize) -> Result<(), TryReserveError> {
+        self.try_reserve(len, 1)
+    }
+
+    /// Exact-size counterpart of [`try_reserve`](Self::try_reserve): grows
+    /// the allocation to exactly fit `additional` more elements beyond `len`,
+    /// without the amortized doubling.
+    pub fn try_reserve_exact(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.cap.wrapping_sub(len) >= additional {
+            return Ok(());
+        }
+
+        let required_cap = len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        self.try_grow_to(required_cap)
+    }
+
+    /// Reserves capacity for at least `additional` more elements beyond
+    /// `len`, panicking on capacity overflow and aborting on allocation
+    /// failure.
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        self.try_reserve(len, additional)
+            .unwrap_or_else(|err| handle_error(err));
+    }
+
+    /// Reserves capacity for at least one more element beyond `len`, with
+    /// the same amortized growth as [`reserve`](Self::reserve); used by
+    /// `push` on the slow path where `len == capacity`.
+    pub fn reserve_for_push(&mut self, len: usize) {
+        self.try_reserve_for_push(len)
+            .unwrap_or_else(|err| handle_error(err));
+    }
+}
+
+impl<T, A: Allocator> Drop for RawVec<T, A> {
+    fn drop(&mut self) {
+        if let Some((ptr, layout)) = self.current_memory() {
+            unsafe { self.alloc.deallocate(ptr, layout) }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+use super::alloc::{Allocator, Global};
+use super::raw_vec::RawVec;
+use super::vec::Vec;
+
+/// The refcounted allocation a family of [`SharedSlice`]s was frozen from.
+///
+/// Holding the `RawVec` here (rather than in `SharedSlice` itself) means
+/// the backing memory is freed exactly once, when the last view drops.
+struct Shared<T, A: Allocator> {
+    buf: RawVec<T, A>,
+    /// Number of initialized elements in `buf` at freeze time, so `Drop`
+    /// knows how much of the buffer to run destructors over rather than
+    /// reading past the initialized prefix into spare capacity.
+    len: usize,
+    refs: AtomicUsize,
+}
+
+impl<T, A: Allocator> Drop for Shared<T, A> {
+    fn drop(&mut self) {
+        // SAFETY: `len` is exactly the number of elements initialized by
+        // `Vec::freeze`, and this runs before `buf` is dropped, so the
+        // elements are destroyed before the allocation backing them is
+        // freed.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len));
+        }
+    }
+}
+
+/// A reference-counted, read-only view over a buffer that was previously
+/// an exclusively-owned [`Vec`], created via [`Vec::freeze`].
+///
+/// This is the `BytesMut`-style split/freeze handoff: a writer fills a
+/// `Vec`, then `freeze`s it so any number of readers can share the same
+/// buffer without copying.
+pub struct SharedSlice<T, A: Allocator = Global> {
+    inner: NonNull<Shared<T, A>>,
+    offset: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: `SharedSlice` only ever hands out shared access to the `T`s it
+// owns (it has no interior mutability of its own), and the refcount
+// protecting the final `Shared` drop is atomic with the same
+// Release/Acquire pairing `Arc` uses. So, exactly like `Arc<T>`, both
+// sending a `SharedSlice<T, A>` to another thread and letting two threads
+// observe `&SharedSlice<T, A>` concurrently require `T`/`A` to be `Send`
+// and `Sync`: a clone left behind shares the same elements, and whichever
+// thread drops the last reference drops them.
+unsafe impl<T: Send + Sync, A: Allocator + Send + Sync> Send for SharedSlice<T, A> {}
+unsafe impl<T: Send + Sync, A: Allocator + Send + Sync> Sync for SharedSlice<T, A> {}
+
+impl<T, A: Allocator> Vec<T, A> {
+    /// Converts this exclusively-owned `Vec` into a cheaply-clonable,
+    /// immutable [`SharedSlice`] without copying its elements.
+    pub fn freeze(self) -> SharedSlice<T, A> {
+        let (buf, len) = self.into_raw_parts();
+        let shared = Shared {
+            buf,
+            len,
+            refs: AtomicUsize::new(1),
+        };
+        let inner = NonNull::from(Box::leak(Box::new(shared)));
+        SharedSlice {
+            inner,
+            offset: 0,
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> SharedSlice<T, A> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe {
+            let base = self.inner.as_ref().buf.ptr().add(self.offset);
+            slice::from_raw_parts(base, self.len)
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for SharedSlice<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> Clone for SharedSlice<T, A> {
+    fn clone(&self) -> Self {
+        // SAFETY: `self` is a live reference, so `inner` is valid and the
+        // refcount it points at is still being kept alive by `self`.
+        unsafe { self.inner.as_ref().refs.fetch_add(1, Ordering::Relaxed) };
+        Self {
+            inner: self.inner,
+            offset: self.offset,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for SharedSlice<T, A> {
+    fn drop(&mut self) {
+        // SAFETY: see `Clone`; the release/acquire pairing here is the
+        // standard `Arc` drop idiom so the final dropper observes every
+        // prior reader's accesses before freeing the buffer.
+        unsafe {
+            if self.inner.as_ref().refs.fetch_sub(1, Ordering::Release) == 1 {
+                atomic::fence(Ordering::Acquire);
+                drop(Box::from_raw(self.inner.as_ptr()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freeze_shares_without_copying() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..8 {
+            v.push(i);
+        }
+        let shared = v.freeze();
+        let other = shared.clone();
+        assert_eq!(&shared[..], &[0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(&other[..], &shared[..]);
+        drop(shared);
+        assert_eq!(&other[..], &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn drop_runs_destructors_once_last_reference_drops() {
+        use super::super::super::test_support::DropCounter;
+        use std::cell::Cell;
+
+        let count = Cell::new(0);
+        let mut v = Vec::new();
+        for _ in 0..5 {
+            v.push(DropCounter(&count));
+        }
+        let shared = v.freeze();
+        let other = shared.clone();
+        drop(shared);
+        assert_eq!(count.get(), 0, "elements must outlive any single reference");
+        drop(other);
+        assert_eq!(count.get(), 5);
+    }
+}
@@ -1,17 +1,18 @@
 use std::marker::PhantomData;
-use std::mem::ManuallyDrop;
+use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::{Deref, DerefMut};
 use std::{ptr, slice};
 
+use super::alloc::{Allocator, Global};
 use super::into_iter::IntoIter;
-use super::raw_vec::RawVec;
+use super::raw_vec::{RawVec, TryReserveError};
 
-pub struct Vec<T> {
-    buf: RawVec<T>,
+pub struct Vec<T, A: Allocator = Global> {
+    buf: RawVec<T, A>,
     len: usize,
 }
 
-impl<T> Vec<T> {
+impl<T> Vec<T, Global> {
     pub const fn new() -> Self {
         Self {
             buf: RawVec::NEW,
@@ -32,6 +33,27 @@ impl<T> Vec<T> {
             len: 0,
         }
     }
+}
+
+impl<T, A: Allocator> Vec<T, A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            buf: RawVec::new_in(alloc),
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            buf: RawVec::with_capacity_in(capacity, alloc),
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
 
     #[inline]
     pub fn len(&self) -> usize {
@@ -53,12 +75,40 @@ impl<T> Vec<T> {
         self.buf.ptr()
     }
 
+    /// Forces the length of the vector to `len`.
+    ///
+    /// # Safety
+    ///
+    /// `len` must be `<= capacity()`, and every element in `[0, len)` must
+    /// already be initialized (e.g. via [`spare_capacity_mut`](Self::spare_capacity_mut)).
     #[inline]
-    pub(crate) unsafe fn set_len(&mut self, len: usize) {
+    pub unsafe fn set_len(&mut self, len: usize) {
         assert!(len <= self.capacity());
         self.len = len;
     }
 
+    /// Decomposes the `Vec` into its backing `RawVec` and length without
+    /// running drop glue, for in-crate conversions (`IntoIter`, `freeze`)
+    /// that take over ownership of the allocation.
+    pub(crate) fn into_raw_parts(self) -> (RawVec<T, A>, usize) {
+        let me = ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&me.buf) };
+        (buf, me.len)
+    }
+
+    /// Reserves capacity for at least `additional` more elements, returning
+    /// `Err` instead of aborting if the allocation cannot be satisfied.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve(self.len, additional)
+    }
+
+    /// Reserves capacity for exactly `additional` more elements, without
+    /// the amortized over-allocation `try_reserve` uses, returning `Err`
+    /// instead of aborting if the allocation cannot be satisfied.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve_exact(self.len, additional)
+    }
+
     pub fn push(&mut self, value: T) {
         // if length reached capacity, request an additional space of 1
         if self.len == self.capacity() {
@@ -149,21 +199,190 @@ impl<T> Vec<T> {
             value
         }
     }
+
+    /// Returns the remaining spare capacity as a slice of `MaybeUninit<T>`.
+    ///
+    /// Paired with [`set_len`](Self::set_len), this lets callers write
+    /// directly into reserved-but-uninitialized space (the fast-buffering
+    /// idiom), avoiding redundant bounds checks and initialization.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len = self.len();
+        let cap = self.capacity();
+        unsafe {
+            let ptr = self.as_mut_ptr().add(len).cast::<MaybeUninit<T>>();
+            slice::from_raw_parts_mut(ptr, cap - len)
+        }
+    }
+
+    /// Clones and appends every element of `other`, reserving the
+    /// required capacity with a single call up front rather than growing
+    /// on each push.
+    ///
+    /// Every element is cloned one at a time, `T: Copy` types included:
+    /// this function only knows `T: Clone`, so its call to
+    /// [`SpecExtend`] always resolves to the `Clone` impl.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        self.buf.reserve(len, other.len());
+        // SAFETY: `reserve` guaranteed room for `other.len()` more
+        // elements, and `spec_extend` initializes exactly that many
+        // starting at `dst`.
+        unsafe {
+            other.spec_extend(self.as_mut_ptr().add(len));
+            self.set_len(len + other.len());
+        }
+    }
+
+    /// Resizes the vector in place so that `len() == new_len`, cloning
+    /// `value` into any newly added slots or dropping the trailing
+    /// elements if `new_len` is shorter than the current length.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.buf.reserve(len, new_len - len);
+            let spare = &mut self.spare_capacity_mut()[..new_len - len];
+            if let Some((last, rest)) = spare.split_last_mut() {
+                for slot in rest {
+                    slot.write(value.clone());
+                }
+                last.write(value);
+            }
+            // SAFETY: every slot in `[len, new_len)` was just initialized.
+            unsafe { self.set_len(new_len) };
+        } else {
+            self.truncate_len(new_len);
+        }
+    }
+
+    /// Resizes the vector in place, filling any newly added slots by
+    /// calling `f` once per slot (in order).
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.buf.reserve(len, new_len - len);
+            for i in len..new_len {
+                self.spare_capacity_mut()[0].write(f());
+                // SAFETY: the slot at index `i` was just initialized, and
+                // every slot before it already was.
+                unsafe { self.set_len(i + 1) };
+            }
+        } else {
+            self.truncate_len(new_len);
+        }
+    }
+
+    /// Drops the trailing `[new_len, len())` elements and shortens the
+    /// vector to `new_len`. No-op if `new_len >= len()`.
+    fn truncate_len(&mut self, new_len: usize) {
+        let len = self.len();
+        if new_len >= len {
+            return;
+        }
+        unsafe {
+            let tail = slice::from_raw_parts_mut(self.as_mut_ptr().add(new_len), len - new_len);
+            self.set_len(new_len);
+            ptr::drop_in_place(tail);
+        }
+    }
+
+    /// Splits the vector into two at `at`, returning a newly allocated
+    /// `Vec` holding `[at, len)` and leaving `[0, at)` in `self`.
+    ///
+    /// Elements are transferred with a single bulk copy rather than one
+    /// push at a time, since the move doesn't require `T: Copy` (the
+    /// source range's ownership moves to the returned `Vec`; nothing is
+    /// left behind to duplicate-drop).
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        let len = self.len();
+        assert!(at <= len, "`at` out of bounds");
+
+        let tail_len = len - at;
+        let mut other = Vec::with_capacity_in(tail_len, self.allocator().clone());
+
+        unsafe {
+            self.set_len(at);
+            other.set_len(tail_len);
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), tail_len);
+        }
+
+        other
+    }
+}
+
+impl<T, A: Allocator> Drop for Vec<T, A> {
+    fn drop(&mut self) {
+        // `RawVec`'s own `Drop` only frees the allocation; dropping the
+        // live elements in `[0, len)` is our responsibility.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len));
+        }
+    }
+}
+
+/// Writes a slice into uninitialized memory: the `T: Copy` impl with a
+/// single `ptr::copy_nonoverlapping`, the `T: Clone` impl with one
+/// `clone()` call per element.
+///
+/// This is the "autoref specialization" trick rather than the
+/// nightly-only `#![feature(specialization)]`: the two impls take `self`
+/// by value at different reference depths (`&[T]` vs `&&[T]`), so a call
+/// on `other: &[T]` picks the `Copy` impl only when `T: Copy` is known at
+/// the call site, and otherwise the `Clone` impl (via one extra autoref).
+/// In [`Vec::extend_from_slice`], which only bounds `T: Clone`, that
+/// means the `Clone` impl is used for every `T`.
+trait SpecExtend<T> {
+    unsafe fn spec_extend(self, dst: *mut T);
+}
+
+impl<'a, T: Clone> SpecExtend<T> for &'a &'a [T] {
+    unsafe fn spec_extend(self, dst: *mut T) {
+        for (i, item) in self.iter().enumerate() {
+            unsafe { ptr::write(dst.add(i), item.clone()) };
+        }
+    }
 }
 
-impl<T> Deref for Vec<T> {
+impl<T: Copy> SpecExtend<T> for &[T] {
+    unsafe fn spec_extend(self, dst: *mut T) {
+        unsafe { ptr::copy_nonoverlapping(self.as_ptr(), dst, self.len()) };
+    }
+}
+
+impl<T, A: Allocator> Deref for Vec<T, A> {
     type Target = [T];
     fn deref(&self) -> &[T] {
         unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 }
 
-impl<T> DerefMut for Vec<T> {
+impl<T, A: Allocator> DerefMut for Vec<T, A> {
     fn deref_mut(&mut self) -> &mut [T] {
         unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
     }
 }
 
+impl<T, A: Allocator> IntoIterator for Vec<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        let (buf, len) = self.into_raw_parts();
+        IntoIter::new(buf, len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +421,27 @@ mod tests {
         v.push(ZST);
     }
 
+    #[test]
+    fn try_reserve_grows_capacity() {
+        let mut v = Vec::<usize>::new();
+        assert!(v.try_reserve(10).is_ok());
+        assert!(v.capacity() >= 10);
+    }
+
+    #[test]
+    fn try_reserve_overflow_is_err() {
+        let mut v = Vec::<usize>::new();
+        unsafe { v.set_len(0) };
+        assert_eq!(
+            v.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(
+            v.try_reserve_exact(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+    }
+
     #[test]
     fn cap_test() {
         let mut v = Vec::<usize>::new();
@@ -214,4 +454,140 @@ mod tests {
         }
         assert_eq!((v.capacity(), v.len()), (128, 100));
     }
+
+    #[test]
+    fn extend_from_slice_appends_clones() {
+        let mut v = Vec::<usize>::new();
+        v.push(1);
+        v.extend_from_slice(&[2, 3, 4]);
+        assert_eq!(&v[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_copy_element_type() {
+        // Appends a `Copy` element type (`u8`); like every other `T`, it is
+        // written through the `Clone` impl of `SpecExtend`.
+        let mut v = Vec::<u8>::new();
+        v.push(1);
+        v.extend_from_slice(&[2, 3, 4, 5]);
+        assert_eq!(&v[..], &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn resize_grows_and_shrinks() {
+        let mut v = Vec::<usize>::new();
+        v.resize(3, 7);
+        assert_eq!(&v[..], &[7, 7, 7]);
+        v.resize(1, 0);
+        assert_eq!(&v[..], &[7]);
+        v.resize(1, 0);
+        assert_eq!(&v[..], &[7]);
+    }
+
+    #[test]
+    fn resize_with_calls_closure_per_new_slot() {
+        let mut next = 0;
+        let mut v = Vec::<usize>::new();
+        v.resize_with(4, || {
+            next += 1;
+            next
+        });
+        assert_eq!(&v[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn spare_capacity_mut_exposes_uninitialized_tail() {
+        let mut v = Vec::<usize>::with_capacity(4);
+        v.push(1);
+        assert_eq!(v.spare_capacity_mut().len(), 3);
+    }
+
+    #[test]
+    fn split_off_transfers_tail() {
+        let mut v = Vec::<usize>::new();
+        for i in 0..10 {
+            v.push(i);
+        }
+        let tail = v.split_off(6);
+        assert_eq!(&v[..], &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(&tail[..], &[6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn new_in_uses_given_allocator() {
+        let mut v = Vec::new_in(Global);
+        v.push(1);
+        v.push(2);
+        assert_eq!(&v[..], &[1, 2]);
+    }
+
+    #[test]
+    fn new_in_routes_through_a_non_default_allocator() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        use super::super::alloc::AllocError;
+
+        // Delegates to `Global` but counts calls, so we can assert `Vec`
+        // actually goes through the allocator it was constructed with
+        // rather than silently falling back to the global heap.
+        #[derive(Clone)]
+        struct CountingAllocator {
+            allocations: Rc<Cell<usize>>,
+            deallocations: Rc<Cell<usize>>,
+        }
+
+        unsafe impl Allocator for CountingAllocator {
+            fn allocate(&self, layout: std::alloc::Layout) -> Result<ptr::NonNull<[u8]>, AllocError> {
+                self.allocations.set(self.allocations.get() + 1);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: ptr::NonNull<u8>, layout: std::alloc::Layout) {
+                self.deallocations.set(self.deallocations.get() + 1);
+                unsafe { Global.deallocate(ptr, layout) };
+            }
+        }
+
+        let allocations = Rc::new(Cell::new(0));
+        let deallocations = Rc::new(Cell::new(0));
+        let alloc = CountingAllocator {
+            allocations: allocations.clone(),
+            deallocations: deallocations.clone(),
+        };
+
+        let mut v = Vec::new_in(alloc);
+        for i in 0..20 {
+            v.push(i);
+        }
+        assert!(
+            allocations.get() >= 1,
+            "Vec::new_in must route growth through the given Allocator"
+        );
+
+        // The default `Allocator::grow` reallocates-and-copies, so pushing
+        // past capacity already deallocates the old buffer through our
+        // allocator; only dropping the `Vec` itself must add one more.
+        let deallocations_before_drop = deallocations.get();
+        drop(v);
+        assert_eq!(
+            deallocations.get(),
+            deallocations_before_drop + 1,
+            "dropping the Vec must route deallocation through the given Allocator"
+        );
+    }
+
+    #[test]
+    fn drop_runs_destructors_for_every_live_element() {
+        use super::super::super::test_support::DropCounter;
+        use std::cell::Cell;
+
+        let count = Cell::new(0);
+        let mut v = Vec::new();
+        for _ in 0..5 {
+            v.push(DropCounter(&count));
+        }
+        drop(v);
+        assert_eq!(count.get(), 5);
+    }
 }